@@ -0,0 +1,210 @@
+// a hole of hole_size elements followed by data_size already-filled elements;
+// a full Assembler::contigs listing covers the window, hole-then-data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contig {
+    pub hole_size: usize,
+    pub data_size: usize,
+}
+
+// returned by Assembler::add when the range falls outside the tracked
+// window, or accepting it would exceed Assembler::MAX_HOLES
+#[derive(Debug)]
+pub enum AssemblerError {
+    OutOfWindow,
+    TooManyHoles,
+}
+
+// tracks which elements of a fixed-size window have been filled in, so data
+// arriving out of order (e.g. TCP segments) can be accepted at arbitrary
+// offsets while only the contiguous prefix is exposed; pairs with
+// RingB::get_unallocated_mut and RingB::enqueue_unallocated
+pub struct Assembler {
+    size: usize,
+    // sorted, non-overlapping, non-adjacent filled ranges, each [start, end)
+    filled: Vec<(usize, usize)>,
+}
+
+impl Assembler {
+
+    // upper bound on the number of holes tracked at once
+    pub const MAX_HOLES: usize = 32;
+
+    // creates an assembler over a window of size elements, entirely unfilled
+    pub fn new(size: usize) -> Assembler {
+        Assembler { size, filled: Vec::new() }
+    }
+
+    // records that [offset, offset + len) has been filled in, merging with
+    // any overlapping or adjacent ranges, and returns the number of elements
+    // starting at offset 0 that are now contiguously filled
+    pub fn add(&mut self, offset: usize, len: usize) -> Result<usize, AssemblerError> {
+
+        if len == 0 {
+            return Ok(self.front_len());
+        }
+
+        if offset.checked_add(len).is_none_or(|end| end > self.size) {
+            return Err(AssemblerError::OutOfWindow);
+        }
+
+        let mut merged = self.filled.clone();
+        Self::insert_merge(&mut merged, offset, offset + len);
+
+        if Self::hole_count(&merged, self.size) > Self::MAX_HOLES {
+            return Err(AssemblerError::TooManyHoles);
+        }
+
+        self.filled = merged;
+        Ok(self.front_len())
+    }
+
+    // returns the compact hole/data extent list covering the whole window
+    pub fn contigs(&self) -> Vec<Contig> {
+
+        let mut out = Vec::new();
+        let mut cursor = 0;
+
+        for &(start, end) in &self.filled {
+            out.push(Contig { hole_size: start - cursor, data_size: end - start });
+            cursor = end;
+        }
+
+        if cursor < self.size {
+            out.push(Contig { hole_size: self.size - cursor, data_size: 0 });
+        }
+
+        out
+    }
+
+    fn front_len(&self) -> usize {
+        match self.filled.first() {
+            Some(&(0, end)) => end,
+            _ => 0,
+        }
+    }
+
+    fn insert_merge(filled: &mut Vec<(usize, usize)>, mut start: usize, mut end: usize) {
+
+        let mut i = 0;
+        while i < filled.len() {
+            let (s, e) = filled[i];
+
+            if e < start {
+                i += 1;
+                continue;
+            }
+            if s > end {
+                break;
+            }
+
+            // overlaps or touches the new range: fold it in and keep scanning
+            start = start.min(s);
+            end = end.max(e);
+            filled.remove(i);
+        }
+
+        filled.insert(i, (start, end));
+    }
+
+    fn hole_count(filled: &[(usize, usize)], size: usize) -> usize {
+
+        let mut count = 0;
+        let mut cursor = 0;
+
+        for &(start, end) in filled {
+            if start > cursor {
+                count += 1;
+            }
+            cursor = end;
+        }
+
+        if cursor < size {
+            count += 1;
+        }
+
+        count
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn add_in_order() {
+
+        let mut a = Assembler::new(10);
+
+        assert_eq!(a.add(0, 4).unwrap(), 4);
+        assert_eq!(a.add(4, 3).unwrap(), 7);
+    }
+
+    #[test]
+    fn add_out_of_order() {
+
+        let mut a = Assembler::new(10);
+
+        // a gap, then a later segment: nothing contiguous at the front yet
+        assert_eq!(a.add(4, 3).unwrap(), 0);
+
+        // fill the gap: now the whole front run merges
+        assert_eq!(a.add(0, 4).unwrap(), 7);
+    }
+
+    #[test]
+    fn overlapping_ranges_merge() {
+
+        let mut a = Assembler::new(10);
+
+        a.add(0, 3).unwrap();
+        a.add(2, 3).unwrap();
+
+        assert_eq!(a.contigs(), vec![
+            Contig { hole_size: 0, data_size: 5 },
+            Contig { hole_size: 5, data_size: 0 },
+        ]);
+    }
+
+    #[test]
+    fn contigs_report_holes_and_data() {
+
+        let mut a = Assembler::new(10);
+
+        a.add(2, 2).unwrap();
+        a.add(6, 2).unwrap();
+
+        assert_eq!(a.contigs(), vec![
+            Contig { hole_size: 2, data_size: 2 },
+            Contig { hole_size: 2, data_size: 2 },
+            Contig { hole_size: 2, data_size: 0 },
+        ]);
+    }
+
+    #[test]
+    fn too_many_holes_is_rejected() {
+
+        let mut a = Assembler::new(Assembler::MAX_HOLES * 4);
+
+        // punch isolated, non-adjacent segments until only the trailing
+        // hole remains within budget
+        for i in 0..Assembler::MAX_HOLES - 1 {
+            a.add(i * 4 + 1, 1).unwrap();
+        }
+
+        // one more isolated segment pushes the hole count past the budget
+        assert!(a.add((Assembler::MAX_HOLES - 1) * 4 + 1, 1).is_err());
+    }
+
+    #[test]
+    fn add_rejects_ranges_outside_the_window() {
+
+        let mut a = Assembler::new(10);
+
+        assert!(matches!(a.add(0, 11), Err(AssemblerError::OutOfWindow)));
+        assert!(matches!(a.add(8, 3), Err(AssemblerError::OutOfWindow)));
+        assert!(matches!(a.add(usize::MAX, 1), Err(AssemblerError::OutOfWindow)));
+    }
+
+}
@@ -1,10 +1,17 @@
+use std::mem::MaybeUninit;
+
+mod spsc;
+pub use spsc::{Consumer, Producer};
+
+mod assembler;
+pub use assembler::{Assembler, AssemblerError, Contig};
 
 pub struct RingB<T> {
     head: usize,
     tail: usize,
     size: usize,
     capacity: usize,
-    items: Vec<Option<T>>,
+    items: Box<[MaybeUninit<T>]>,
 }
 
 pub struct OverflowError;
@@ -19,11 +26,11 @@ impl<T> RingB<T> {
 
     pub fn with_capacity(capacity: usize) -> Self {
 
-        // allocate a buffer initialized at None
+        // allocate a buffer of uninitialized slots
         let mut v = Vec::with_capacity(capacity);
-        v.resize_with(capacity, || None);
-        
-        RingB { head: 0, tail: 0, size: 0, capacity, items: v }
+        v.resize_with(capacity, MaybeUninit::uninit);
+
+        RingB { head: 0, tail: 0, size: 0, capacity, items: v.into_boxed_slice() }
     }
 
     pub fn capacity(&self) -> usize {
@@ -39,7 +46,7 @@ impl<T> RingB<T> {
     }
 
     pub fn is_full(&self)-> bool {
-        self.size == self.capacity    
+        self.size == self.capacity
     }
 
     pub fn enqueue(&mut self, item: T) {
@@ -50,9 +57,9 @@ impl<T> RingB<T> {
         }
 
         // store item in the next slot
-        self.items[self.tail] = Some(item);
+        self.items[self.tail] = MaybeUninit::new(item);
         self.tail = (self.tail + 1) % self.capacity;
-        
+
         // update buffer size
         debug_assert!(self.size < self.capacity);
         self.size += 1;
@@ -66,7 +73,7 @@ impl<T> RingB<T> {
         }
 
         // store item in the next slot
-        self.items[self.tail] = Some(item);
+        self.items[self.tail] = MaybeUninit::new(item);
         self.tail = (self.tail + 1) % self.capacity;
 
         // update buffer size
@@ -84,9 +91,8 @@ impl<T> RingB<T> {
             return None;
         }
 
-        // retrieve current item and leave None in its place
-        let item = self.items[self.head].take();
-        debug_assert!(item.is_some());
+        // retrieve current item, moving it out of its slot
+        let item = unsafe { self.items[self.head].assume_init_read() };
 
         // advance tail index
         self.head = (self.head + 1) % self.capacity;
@@ -96,9 +102,361 @@ impl<T> RingB<T> {
         self.size -= 1;
 
         // return retrieved item
+        Some(item)
+    }
+
+    // pushes onto the front, so it's the next one out via dequeue; drops the
+    // back element to make room when full, mirroring enqueue's drop-oldest
+    pub fn enqueue_front(&mut self, item: T) {
+
+        if self.is_full() {
+            let _ = self.dequeue_back();
+        }
+
+        self.head = (self.head + self.capacity - 1) % self.capacity;
+        self.items[self.head] = MaybeUninit::new(item);
+
+        debug_assert!(self.size < self.capacity);
+        self.size += 1;
+    }
+
+    // removes and returns the element at the back (the tail end), i.e. the
+    // one most recently added with enqueue
+    pub fn dequeue_back(&mut self) -> Option<T> {
+
+        if self.is_empty() {
+            return None;
+        }
+
+        self.tail = (self.tail + self.capacity - 1) % self.capacity;
+        let item = unsafe { self.items[self.tail].assume_init_read() };
+
+        debug_assert!(self.size > 0);
+        self.size -= 1;
+
+        Some(item)
+    }
+
+    // returns allocated data starting offset elements past the head, clamped
+    // at the wrap boundary so the caller must loop to reach the second segment
+    pub fn get_allocated(&self, offset: usize, len: usize) -> &[T] {
+
+        if offset >= self.size {
+            return &[];
+        }
+
+        let start = (self.head + offset) % self.capacity;
+        let available = self.size - offset;
+        let len = len.min(available).min(self.capacity - start);
+
+        unsafe {
+            std::slice::from_raw_parts(self.items[start].as_ptr(), len)
+        }
+    }
+
+    // returns free space starting offset elements past the tail, clamped at
+    // the wrap boundary; slots are uninitialized until written and committed
+    // with enqueue_unallocated
+    pub fn get_unallocated_mut(&mut self, offset: usize, len: usize) -> &mut [MaybeUninit<T>] {
+
+        let free = self.capacity - self.size;
+        if offset >= free {
+            return &mut [];
+        }
+
+        let start = (self.tail + offset) % self.capacity;
+        let available = free - offset;
+        let len = len.min(available).min(self.capacity - start);
+
+        &mut self.items[start..start + len]
+    }
+
+    pub fn enqueue_unallocated(&mut self, count: usize) {
+
+        // count is typically derived from untrusted input (e.g. an
+        // Assembler's reported contiguous length), so enforce it for real
+        assert!(count <= self.capacity - self.size, "enqueue_unallocated: count exceeds free space");
+
+        self.tail = (self.tail + count) % self.capacity;
+        self.size += count;
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+
+        // index counted from the head: 0 is the oldest element
+        if index >= self.size {
+            return None;
+        }
+
+        let pos = (self.head + index) % self.capacity;
+        Some(unsafe { self.items[pos].assume_init_ref() })
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+
+        if index >= self.size {
+            return None;
+        }
+
+        let pos = (self.head + index) % self.capacity;
+        Some(unsafe { self.items[pos].assume_init_mut() })
+    }
+
+    pub fn peek_front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn peek_back(&self) -> Option<&T> {
+        self.get(self.size.checked_sub(1)?)
+    }
+
+    // yields elements in FIFO order, head to tail
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { buf: self, index: 0 }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            items: self.items.as_mut_ptr(),
+            capacity: self.capacity,
+            head: self.head,
+            remaining: self.size,
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    // drops all buffered items and resets the buffer to empty
+    pub fn clear(&mut self) {
+        while self.dequeue().is_some() {}
+        self.head = 0;
+        self.tail = 0;
+    }
+
+    // like clear, but also overwrites every backing slot so no stale element
+    // bytes are left behind in the (now unused) storage
+    pub fn reset(&mut self) {
+        self.clear();
+        for slot in self.items.iter_mut() {
+            *slot = MaybeUninit::zeroed();
+        }
+    }
+
+    // reallocates the backing storage to new_capacity, copying the buffered
+    // elements into a fresh contiguous layout with the head back at index 0;
+    // if new_capacity is smaller than size, the oldest elements are dropped
+    pub fn set_capacity(&mut self, new_capacity: usize) {
+
+        assert!(new_capacity > 0, "set_capacity: new_capacity must be greater than zero");
+
+        while self.size > new_capacity {
+            let _ = self.dequeue();
+        }
+
+        let mut new_items = Vec::with_capacity(new_capacity);
+        while let Some(item) = self.dequeue() {
+            new_items.push(MaybeUninit::new(item));
+        }
+
+        let size = new_items.len();
+        new_items.resize_with(new_capacity, MaybeUninit::uninit);
+
+        self.items = new_items.into_boxed_slice();
+        self.capacity = new_capacity;
+        self.head = 0;
+        self.tail = size % new_capacity;
+        self.size = size;
+    }
+
+}
+
+impl<T: Copy> RingB<T> {
+
+    // copies as many elements of data as fit, returning the count moved
+    pub fn enqueue_slice(&mut self, data: &[T]) -> usize {
+
+        let mut written = 0;
+
+        while written < data.len() {
+
+            let dst = self.get_unallocated_mut(0, data.len() - written);
+            if dst.is_empty() {
+                break;
+            }
+
+            let n = dst.len();
+            for (slot, &value) in dst.iter_mut().zip(&data[written..written + n]) {
+                slot.write(value);
+            }
+            self.enqueue_unallocated(n);
+            written += n;
+        }
+
+        written
+    }
+
+    // copies as many buffered elements as are available into data, removing
+    // them, and returns the count moved
+    pub fn dequeue_slice(&mut self, data: &mut [T]) -> usize {
+
+        let mut read = 0;
+
+        while read < data.len() {
+
+            let src = self.get_allocated(0, data.len() - read);
+            if src.is_empty() {
+                break;
+            }
+
+            let n = src.len();
+            data[read..read + n].copy_from_slice(src);
+            self.head = (self.head + n) % self.capacity;
+            self.size -= n;
+            read += n;
+        }
+
+        read
+    }
+
+}
+
+impl<T> Drop for RingB<T> {
+
+    fn drop(&mut self) {
+        // dequeue drains remaining items, running their destructors
+        while self.dequeue().is_some() {}
+    }
+
+}
+
+// iterator over &T, produced by RingB::iter
+pub struct Iter<'a, T> {
+    buf: &'a RingB<T>,
+    index: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.buf.get(self.index);
+        if item.is_some() {
+            self.index += 1;
+        }
         item
     }
 
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.buf.size() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+// iterator over &mut T, produced by RingB::iter_mut
+pub struct IterMut<'a, T> {
+    items: *mut MaybeUninit<T>,
+    capacity: usize,
+    head: usize,
+    remaining: usize,
+    _marker: std::marker::PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let pos = self.head;
+        self.head = (self.head + 1) % self.capacity;
+        self.remaining -= 1;
+
+        // each yielded index is distinct, so the mutable references never alias
+        let slot = unsafe { &mut *self.items.add(pos) };
+        Some(unsafe { slot.assume_init_mut() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+// draining iterator, produced by RingB::into_iter
+pub struct IntoIter<T> {
+    buf: RingB<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        self.buf.dequeue()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.buf.size();
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> IntoIterator for RingB<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        IntoIter { buf: self }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a RingB<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut RingB<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T> FromIterator<T> for RingB<T> {
+
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let iter = iter.into_iter();
+
+        // size the buffer to the input when known, otherwise fall back to
+        // the default capacity and overwrite on overflow like enqueue
+        let capacity = match iter.size_hint() {
+            (_, Some(upper)) if upper > 0 => upper,
+            _ => DEFAULT_BUFFER_CAPACITY,
+        };
+
+        let mut buf = RingB::with_capacity(capacity);
+        for item in iter {
+            buf.enqueue(item);
+        }
+
+        buf
+    }
+}
+
+impl<T: Clone> Clone for RingB<T> {
+
+    fn clone(&self) -> Self {
+        let mut buf = RingB::with_capacity(self.capacity);
+        for item in self.iter() {
+            buf.enqueue(item.clone());
+        }
+        buf
+    }
 }
 
 
@@ -238,9 +596,10 @@ mod tests {
     #[test]
     fn simple_string_ref() {
 
-        let mut b = RingB::with_capacity(10);
-
+        // `s` must outlive `b`: RingB now has a Drop impl, so dropck
+        // requires borrowed data to still be valid when `b` is dropped.
         let s = String::from("hello");
+        let mut b = RingB::with_capacity(10);
         b.enqueue(&s);
 
         // s still valid
@@ -251,4 +610,317 @@ mod tests {
 
     }
 
+    #[test]
+    fn enqueue_slice() {
+
+        let mut b = RingB::with_capacity(4);
+
+        assert_eq!(b.enqueue_slice(&[1, 2, 3]), 3);
+        assert_eq!(b.size(), 3);
+
+        // only 1 slot left, extra elements are dropped
+        assert_eq!(b.enqueue_slice(&[4, 5]), 1);
+        assert_eq!(b.size(), 4);
+        assert!(b.is_full());
+    }
+
+    #[test]
+    fn dequeue_slice() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3, 4]);
+
+        let mut out = [0; 3];
+        assert_eq!(b.dequeue_slice(&mut out), 3);
+        assert_eq!(out, [1, 2, 3]);
+        assert_eq!(b.size(), 1);
+
+        let mut out = [0; 3];
+        assert_eq!(b.dequeue_slice(&mut out), 1);
+        assert_eq!(out, [4, 0, 0]);
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn get_allocated_clamps_at_wrap_boundary() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3, 4]);
+
+        // make head wrap past the end of the backing storage
+        let _ = b.dequeue();
+        let _ = b.dequeue();
+        b.enqueue_slice(&[5, 6]);
+
+        // allocated region is [3, 4, 5, 6] but the first segment stops at
+        // the end of the backing storage
+        assert_eq!(b.get_allocated(0, 10), &[3, 4]);
+        assert_eq!(b.get_allocated(2, 10), &[5, 6]);
+    }
+
+    #[test]
+    fn get_unallocated_mut_writes_in_place() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2]);
+
+        let free = b.get_unallocated_mut(0, 10);
+        assert_eq!(free.len(), 2);
+        for (slot, value) in free.iter_mut().zip([3, 4]) {
+            slot.write(value);
+        }
+        b.enqueue_unallocated(2);
+
+        let mut out = [0; 4];
+        b.dequeue_slice(&mut out);
+        assert_eq!(out, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn enqueue_unallocated_rejects_count_exceeding_free_space() {
+
+        let mut b = RingB::<u8>::with_capacity(4);
+        b.enqueue_unallocated(100);
+    }
+
+    #[test]
+    fn drop_runs_destructors_on_remaining_items() {
+
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut b = RingB::with_capacity(4);
+
+        b.enqueue(Rc::clone(&counter));
+        b.enqueue(Rc::clone(&counter));
+        assert_eq!(Rc::strong_count(&counter), 3);
+
+        drop(b);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn get() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3]);
+
+        assert_eq!(b.get(0), Some(&1));
+        assert_eq!(b.get(2), Some(&3));
+        assert_eq!(b.get(3), None);
+
+        // wrap head past the end of the backing storage
+        let _ = b.dequeue();
+        b.enqueue_slice(&[4]);
+
+        assert_eq!(b.get(0), Some(&2));
+        assert_eq!(b.get(1), Some(&3));
+        assert_eq!(b.get(2), Some(&4));
+    }
+
+    #[test]
+    fn get_mut() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3]);
+
+        *b.get_mut(1).unwrap() = 20;
+
+        assert_eq!(b.get(1), Some(&20));
+        assert!(b.get_mut(3).is_none());
+    }
+
+    #[test]
+    fn peek_front_and_back() {
+
+        let mut b = RingB::<usize>::with_capacity(4);
+
+        assert_eq!(b.peek_front(), None);
+        assert_eq!(b.peek_back(), None);
+
+        b.enqueue_slice(&[1, 2, 3]);
+
+        assert_eq!(b.peek_front(), Some(&1));
+        assert_eq!(b.peek_back(), Some(&3));
+    }
+
+    #[test]
+    fn iter() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3]);
+
+        let v: Vec<&usize> = b.iter().collect();
+        assert_eq!(v, vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn iter_mut() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3]);
+
+        for item in b.iter_mut() {
+            *item *= 10;
+        }
+
+        let v: Vec<&usize> = b.iter().collect();
+        assert_eq!(v, vec![&10, &20, &30]);
+    }
+
+    #[test]
+    fn into_iter() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3]);
+
+        let v: Vec<usize> = b.into_iter().collect();
+        assert_eq!(v, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn from_iter() {
+
+        let b: RingB<usize> = (1..=5).collect();
+
+        assert_eq!(b.capacity(), 5);
+        assert_eq!(b.size(), 5);
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4, &5]);
+    }
+
+    #[test]
+    fn clone() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3]);
+
+        let c = b.clone();
+        assert_eq!(c.capacity(), b.capacity());
+        assert_eq!(c.iter().collect::<Vec<_>>(), b.iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn enqueue_front() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[2, 3]);
+
+        b.enqueue_front(1);
+
+        assert_eq!(b.size(), 3);
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn enqueue_front_drops_back_when_full() {
+
+        let mut b = RingB::with_capacity(3);
+        b.enqueue_slice(&[1, 2, 3]);
+
+        b.enqueue_front(0);
+
+        assert!(b.is_full());
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![&0, &1, &2]);
+    }
+
+    #[test]
+    fn dequeue_back() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3]);
+
+        assert_eq!(b.dequeue_back(), Some(3));
+        assert_eq!(b.dequeue_back(), Some(2));
+        assert_eq!(b.size(), 1);
+        assert_eq!(b.dequeue_back(), Some(1));
+        assert_eq!(b.dequeue_back(), None);
+    }
+
+    #[test]
+    fn clear() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3]);
+
+        b.clear();
+
+        assert!(b.is_empty());
+        assert_eq!(b.head, 0);
+        assert_eq!(b.tail, 0);
+        assert_eq!(b.capacity(), 4);
+
+        // still usable afterwards
+        b.enqueue_slice(&[4, 5]);
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![&4, &5]);
+    }
+
+    #[test]
+    fn reset() {
+
+        use std::rc::Rc;
+
+        let counter = Rc::new(());
+        let mut b = RingB::with_capacity(4);
+        b.enqueue(Rc::clone(&counter));
+
+        b.reset();
+
+        assert_eq!(Rc::strong_count(&counter), 1);
+        assert!(b.is_empty());
+    }
+
+    #[test]
+    fn set_capacity_grows() {
+
+        let mut b = RingB::with_capacity(2);
+        b.enqueue_slice(&[1, 2]);
+
+        b.set_capacity(4);
+
+        assert_eq!(b.capacity(), 4);
+        assert_eq!(b.size(), 2);
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![&1, &2]);
+
+        b.enqueue_slice(&[3, 4]);
+        assert!(b.is_full());
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![&1, &2, &3, &4]);
+    }
+
+    #[test]
+    fn set_capacity_shrinks_dropping_oldest() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3, 4]);
+
+        b.set_capacity(2);
+
+        assert_eq!(b.capacity(), 2);
+        assert_eq!(b.size(), 2);
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![&3, &4]);
+    }
+
+    #[test]
+    fn set_capacity_linearizes_wrapped_contents() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2, 3, 4]);
+        let _ = b.dequeue();
+        let _ = b.dequeue();
+        b.enqueue_slice(&[5, 6]);
+
+        b.set_capacity(6);
+
+        assert_eq!(b.head, 0);
+        assert_eq!(b.iter().collect::<Vec<_>>(), vec![&3, &4, &5, &6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn set_capacity_rejects_zero() {
+
+        let mut b = RingB::<u8>::with_capacity(4);
+        b.set_capacity(0);
+    }
+
 }
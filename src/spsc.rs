@@ -0,0 +1,151 @@
+use std::cell::UnsafeCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use crate::{OverflowError, RingB};
+
+// head is only ever written by the consumer, tail only by the producer;
+// both counters increase monotonically (never wrap modulo capacity), so
+// "empty" (head == tail) and "full" (tail - head == capacity) are unambiguous
+struct Shared<T> {
+    items: Box<[UnsafeCell<Option<T>>]>,
+    capacity: usize,
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// SAFETY: access to `items` is partitioned by construction: only the
+// producer ever writes the slot at `tail % capacity`, only the consumer
+// ever writes (via `take`) the slot at `head % capacity`, and each side
+// only reads a slot after observing (with Acquire) that the other side has
+// published it.
+unsafe impl<T: Send> Send for Shared<T> {}
+unsafe impl<T: Send> Sync for Shared<T> {}
+
+pub struct Producer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+pub struct Consumer<T> {
+    shared: Arc<Shared<T>>,
+}
+
+impl<T> Producer<T> {
+
+    pub fn enqueue_or_overflow(&mut self, item: T) -> Result<(), OverflowError> {
+
+        let tail = self.shared.tail.load(Ordering::Relaxed);
+        let head = self.shared.head.load(Ordering::Acquire);
+
+        if tail - head == self.shared.capacity {
+            return Err(OverflowError);
+        }
+
+        let idx = tail % self.shared.capacity;
+        // SAFETY: only the producer ever writes this slot, and the consumer
+        // won't read it until it observes the Release store to `tail` below.
+        unsafe {
+            *self.shared.items[idx].get() = Some(item);
+        }
+
+        self.shared.tail.store(tail + 1, Ordering::Release);
+
+        Ok(())
+    }
+
+}
+
+impl<T> Consumer<T> {
+
+    pub fn dequeue(&mut self) -> Option<T> {
+
+        let head = self.shared.head.load(Ordering::Relaxed);
+        let tail = self.shared.tail.load(Ordering::Acquire);
+
+        if head == tail {
+            return None;
+        }
+
+        let idx = head % self.shared.capacity;
+        // SAFETY: only the consumer ever writes this slot, and the Acquire
+        // load of `tail` above guarantees the producer's write has
+        // happened-before this read.
+        let item = unsafe { (*self.shared.items[idx].get()).take() };
+
+        self.shared.head.store(head + 1, Ordering::Release);
+
+        item
+    }
+
+}
+
+impl<T> RingB<T> {
+
+    // splits into a lock-free producer/consumer pair; buffered elements are
+    // preserved, in FIFO order, as the initial contents of the shared buffer
+    pub fn split(self) -> (Producer<T>, Consumer<T>) {
+
+        let capacity = self.capacity();
+        let size = self.size();
+
+        let mut drained = self.into_iter();
+        let items: Box<[UnsafeCell<Option<T>>]> = (0..capacity)
+            .map(|_| UnsafeCell::new(drained.next()))
+            .collect();
+
+        let shared = Arc::new(Shared {
+            items,
+            capacity,
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(size),
+        });
+
+        (Producer { shared: Arc::clone(&shared) }, Consumer { shared })
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn split_preserves_buffered_elements() {
+
+        let mut b = RingB::with_capacity(4);
+        b.enqueue_slice(&[1, 2]);
+
+        let (_, mut consumer) = b.split();
+
+        assert_eq!(consumer.dequeue(), Some(1));
+        assert_eq!(consumer.dequeue(), Some(2));
+        assert_eq!(consumer.dequeue(), None);
+    }
+
+    #[test]
+    fn split_round_trips_across_threads() {
+
+        let b = RingB::with_capacity(4);
+        let (mut producer, mut consumer) = b.split();
+
+        let sender = std::thread::spawn(move || {
+            for i in 0..100 {
+                while producer.enqueue_or_overflow(i).is_err() {
+                    std::thread::yield_now();
+                }
+            }
+        });
+
+        let mut received = Vec::with_capacity(100);
+        while received.len() < 100 {
+            if let Some(item) = consumer.dequeue() {
+                received.push(item);
+            }
+        }
+
+        sender.join().unwrap();
+        assert_eq!(received, (0..100).collect::<Vec<_>>());
+    }
+
+}